@@ -1,9 +1,29 @@
 use colored::Colorize;
 
-pub fn execute(target: &str) {
+use super::output::{self, AnalysisReport, OutputFormat, StageStatus};
+use super::runner;
+
+pub fn execute(target: &str, format: OutputFormat) {
+    let interpreter = runner::detect_interpreter();
+
+    if format.is_json() {
+        let report = AnalysisReport {
+            target: target.to_string(),
+            status: StageStatus::NotConnected,
+            findings: Vec::new(),
+            interpreter,
+        };
+        output::emit_json(&report);
+        return;
+    }
+
     println!("{}", "[ ANALYZE ] Running static analysis...".yellow().bold());
     println!("  Target: {}", target.cyan());
+    match &interpreter {
+        Some(version) => println!("  Interpreter: {}", version.cyan()),
+        None => println!("  Interpreter: {}", "not found on PATH".red()),
+    }
     println!();
     println!("{}", "  → Static analysis engine not yet connected.".dimmed());
     println!("{}", "  → Phase 3 will wire the Python AST analyzer here.".dimmed());
-}
\ No newline at end of file
+}
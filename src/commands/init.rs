@@ -1,24 +1,207 @@
+use clap::ValueEnum;
 use colored::Colorize;
+use serde::Serialize;
 use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
 
-pub fn execute() {
-    println!("{}", "[ INIT ] Initializing Flint-X project...".yellow().bold());
+/// The distinct workloads Flint-X targets; each produces a differently
+/// tuned `flintx.config.json`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Profile {
+    /// CPU-bound Python workloads (data processing, batch jobs)
+    CpuBound,
+    /// GPU workloads targeting AMD ROCm hardware
+    RocmGpu,
+    /// Long-running web services (FastAPI, Flask, etc.)
+    WebService,
+    /// Bare-bones config with minimal analysis passes enabled
+    Minimal,
+}
+
+impl Profile {
+    pub const ALL: [Profile; 4] = [
+        Profile::CpuBound,
+        Profile::RocmGpu,
+        Profile::WebService,
+        Profile::Minimal,
+    ];
+
+    /// Short human-readable name used for interactive prompts and config files.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Profile::CpuBound => "cpu-bound",
+            Profile::RocmGpu => "rocm-gpu",
+            Profile::WebService => "web-service",
+            Profile::Minimal => "minimal",
+        }
+    }
+
+    /// One-line description of the workload this profile is meant for.
+    pub fn purpose(&self) -> &'static str {
+        match self {
+            Profile::CpuBound => "CPU-bound workloads: data processing pipelines, batch jobs",
+            Profile::RocmGpu => "GPU workloads on AMD hardware: ROCm/HIP kernels, tensor ops",
+            Profile::WebService => "Long-running web services: FastAPI/Flask request handlers",
+            Profile::Minimal => "Bare-bones setup with only the essentials enabled",
+        }
+    }
+
+    fn from_name(input: &str) -> Option<Profile> {
+        Profile::ALL.into_iter().find(|p| p.name() == input)
+    }
+
+    fn config(&self) -> ProjectConfig {
+        match self {
+            Profile::CpuBound => ProjectConfig {
+                version: "0.1.0",
+                project: "flintx-project",
+                profile: self.name(),
+                ai_endpoint: "http://localhost:8000/analyze",
+                ollama_model: "mistral",
+                output_dir: "./flintx_output",
+                analysis_passes: vec!["loops", "allocations", "complexity"],
+                profiling: ProfilingSettings {
+                    sampling_interval_ms: 10,
+                    track_memory: true,
+                },
+                amd_hardware: None,
+            },
+            Profile::RocmGpu => ProjectConfig {
+                version: "0.1.0",
+                project: "flintx-project",
+                profile: self.name(),
+                ai_endpoint: "http://localhost:8000/analyze",
+                ollama_model: "codellama",
+                output_dir: "./flintx_output",
+                analysis_passes: vec!["kernel_launches", "memory_transfers", "occupancy"],
+                profiling: ProfilingSettings {
+                    sampling_interval_ms: 1,
+                    track_memory: true,
+                },
+                amd_hardware: Some(AmdHardwareSettings {
+                    rocm_smi_path: "/opt/rocm/bin/rocm-smi",
+                    target_arch: "gfx1100",
+                }),
+            },
+            Profile::WebService => ProjectConfig {
+                version: "0.1.0",
+                project: "flintx-project",
+                profile: self.name(),
+                ai_endpoint: "http://localhost:8000/analyze",
+                ollama_model: "mistral",
+                output_dir: "./flintx_output",
+                analysis_passes: vec!["request_latency", "io_wait", "n_plus_one"],
+                profiling: ProfilingSettings {
+                    sampling_interval_ms: 50,
+                    track_memory: false,
+                },
+                amd_hardware: None,
+            },
+            Profile::Minimal => ProjectConfig {
+                version: "0.1.0",
+                project: "flintx-project",
+                profile: self.name(),
+                ai_endpoint: "http://localhost:8000/analyze",
+                ollama_model: "mistral",
+                output_dir: "./flintx_output",
+                analysis_passes: vec!["complexity"],
+                profiling: ProfilingSettings {
+                    sampling_interval_ms: 100,
+                    track_memory: false,
+                },
+                amd_hardware: None,
+            },
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct AmdHardwareSettings {
+    rocm_smi_path: &'static str,
+    target_arch: &'static str,
+}
 
-    let config = r#"{
-  "version": "0.1.0",
-  "project": "flintx-project",
-  "ai_endpoint": "http://localhost:8000/analyze",
-  "ollama_model": "mistral",
-  "output_dir": "./flintx_output"
+#[derive(Serialize)]
+struct ProfilingSettings {
+    sampling_interval_ms: u32,
+    track_memory: bool,
 }
-"#;
+
+#[derive(Serialize)]
+struct ProjectConfig {
+    version: &'static str,
+    project: &'static str,
+    profile: &'static str,
+    ai_endpoint: &'static str,
+    ollama_model: &'static str,
+    output_dir: &'static str,
+    analysis_passes: Vec<&'static str>,
+    profiling: ProfilingSettings,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    amd_hardware: Option<AmdHardwareSettings>,
+}
+
+pub fn execute(profile: Option<Profile>) {
+    println!("{}", "[ INIT ] Initializing Flint-X project...".yellow().bold());
+
+    let profile = match profile {
+        Some(p) => p,
+        None => prompt_for_profile(),
+    };
+
+    if Path::new("flintx.config.json").exists() && !confirm_overwrite() {
+        println!("{}", "  Aborted: flintx.config.json left untouched.".dimmed());
+        return;
+    }
+
+    let config = serde_json::to_string_pretty(&profile.config()).expect("config must serialize");
 
     fs::create_dir_all("flintx_output").expect("Failed to create output directory");
-    fs::write("flintx.config.json", config).expect("Failed to write config file");
+    fs::write("flintx.config.json", format!("{config}\n")).expect("Failed to write config file");
 
     println!("{}", "  ✔ Created flintx.config.json".green());
     println!("{}", "  ✔ Created flintx_output/ directory".green());
     println!();
+    println!("  Profile: {}", profile.name().cyan());
     println!("{}", "  Flint-X project ready.".bold());
     println!("  Edit {} to configure your AI endpoint and model.", "flintx.config.json".cyan());
-}
\ No newline at end of file
+}
+
+fn prompt_for_profile() -> Profile {
+    println!();
+    println!("{}", "  Available profiles:".bold());
+    for p in Profile::ALL {
+        println!("    {} {} — {}", "•".cyan(), p.name().cyan(), p.purpose().dimmed());
+    }
+    println!();
+
+    loop {
+        print!("  Select a profile [{}]: ", Profile::CpuBound.name());
+        io::stdout().flush().ok();
+
+        let mut line = String::new();
+        if io::stdin().read_line(&mut line).unwrap_or(0) == 0 {
+            return Profile::CpuBound;
+        }
+        let input = line.trim();
+        if input.is_empty() {
+            return Profile::CpuBound;
+        }
+        match Profile::from_name(input) {
+            Some(p) => return p,
+            None => println!("{}", "  Unrecognized profile, try again.".red()),
+        }
+    }
+}
+
+fn confirm_overwrite() -> bool {
+    print!("  {} already exists, overwrite it? [y/N]: ", "flintx.config.json".cyan());
+    io::stdout().flush().ok();
+
+    let mut line = String::new();
+    if io::stdin().read_line(&mut line).unwrap_or(0) == 0 {
+        return false;
+    }
+    matches!(line.trim().to_lowercase().as_str(), "y" | "yes")
+}
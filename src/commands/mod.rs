@@ -0,0 +1,8 @@
+pub mod analyze;
+pub mod init;
+pub mod optimize;
+pub mod output;
+pub mod profile;
+pub mod run;
+pub mod runner;
+pub mod serve;
@@ -1,9 +1,21 @@
 use colored::Colorize;
 
-pub fn execute(input: Option<String>) {
+use super::output::{self, OptimizeReport, OutputFormat, StageStatus};
+
+pub fn execute(input: Option<String>, format: OutputFormat) {
+    if format.is_json() {
+        let report = OptimizeReport {
+            input,
+            status: StageStatus::NotConnected,
+            recommendations: Vec::new(),
+        };
+        output::emit_json(&report);
+        return;
+    }
+
     println!("{}", "[ OPTIMIZE ] Querying AI optimization layer...".yellow().bold());
 
-    match input {
+    match &input {
         Some(path) => println!("  Input file: {}", path.cyan()),
         None => println!("  Input: {}", "auto (from last run)".dimmed()),
     }
@@ -11,4 +23,4 @@ pub fn execute(input: Option<String>) {
     println!();
     println!("{}", "  → AI layer not yet connected.".dimmed());
     println!("{}", "  → Phase 5 will wire FastAPI + Ollama here.".dimmed());
-}
\ No newline at end of file
+}
@@ -0,0 +1,144 @@
+//! Shared structured-output types for the `--format json` pipeline mode.
+//!
+//! Every subcommand prints colored human text by default. When `--format json`
+//! is passed, commands instead serialize one of the types below to stdout so
+//! downstream tools (CI, the FastAPI/Ollama layer) can consume results without
+//! scraping terminal text.
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+/// Output mode shared by every subcommand via the global `--format` flag.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Colored, human-readable terminal output (default).
+    Text,
+    /// A single JSON document on stdout, no banner or color codes.
+    Json,
+}
+
+impl OutputFormat {
+    pub fn is_json(self) -> bool {
+        matches!(self, OutputFormat::Json)
+    }
+}
+
+/// Status of a single pipeline stage, mirrored in both the plan and the result.
+///
+/// `Pending`, `Skipped`, `Ok`, and `Failed` are part of the schema consumers
+/// should expect once each phase is wired up; today every stage only ever
+/// reports `NotConnected`.
+#[allow(dead_code)]
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StageStatus {
+    Pending,
+    Skipped,
+    NotConnected,
+    Ok,
+    Failed,
+}
+
+/// One stage of the analyze → profile → optimize pipeline, as emitted by `run`.
+#[derive(Clone, Debug, Serialize)]
+pub struct StageResult {
+    /// Stage name, e.g. "analyze", "profile", "optimize".
+    pub stage: String,
+    /// Input paths the stage consumed.
+    pub inputs: Vec<String>,
+    /// Output artifact paths the stage produced, under `flintx_output/`.
+    pub outputs: Vec<String>,
+    pub status: StageStatus,
+    /// Human-readable detail, e.g. why a stage is not yet connected.
+    pub detail: Option<String>,
+}
+
+/// The full ordered pipeline plan emitted by `flintx run --format json`.
+#[derive(Clone, Debug, Serialize)]
+pub struct PipelinePlan {
+    pub target: String,
+    pub stages: Vec<StageResult>,
+}
+
+/// Findings schema emitted by `flintx analyze --format json`.
+#[derive(Clone, Debug, Serialize)]
+pub struct AnalysisReport {
+    pub target: String,
+    pub status: StageStatus,
+    pub findings: Vec<String>,
+    /// Version string of the Python interpreter located on PATH, if any.
+    pub interpreter: Option<String>,
+}
+
+/// Findings schema emitted by `flintx profile --format json`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ProfileReport {
+    pub target: String,
+    pub status: StageStatus,
+    pub samples: Vec<String>,
+    /// Version string of the Python interpreter located on PATH, if any.
+    pub interpreter: Option<String>,
+}
+
+/// Findings schema emitted by `flintx optimize --format json`.
+#[derive(Clone, Debug, Serialize)]
+pub struct OptimizeReport {
+    pub input: Option<String>,
+    pub status: StageStatus,
+    pub recommendations: Vec<String>,
+}
+
+/// A single optimization hint anchored to a location in the analyzed source,
+/// as streamed back by `flintx serve`'s `analyze` method.
+#[derive(Clone, Debug, Serialize)]
+pub struct OptimizationHint {
+    /// 1-based line number the hint applies to.
+    pub line: u32,
+    /// 1-based column number the hint applies to.
+    pub column: u32,
+    pub message: String,
+    /// Proposed replacement text, when the hint has a concrete fix.
+    pub suggested_change: Option<String>,
+}
+
+/// Result of `flintx serve`'s `analyze` method: the same stage-status schema
+/// as the batch `AnalysisReport`, but with positioned hints an editor can
+/// render inline instead of a flat findings list.
+#[derive(Clone, Debug, Serialize)]
+pub struct ServeAnalysisResult {
+    pub target: String,
+    pub status: StageStatus,
+    pub hints: Vec<OptimizationHint>,
+    /// Version string of the Python interpreter located on PATH, if any.
+    pub interpreter: Option<String>,
+}
+
+/// Timing statistics for a `flintx profile --bench` run, written to
+/// `flintx_output/bench.json` keyed by script path so `optimize` can compare
+/// before/after timings across runs.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BenchReport {
+    pub target: String,
+    pub iterations: u32,
+    pub warmup: u32,
+    /// Wall-clock time of each timed iteration, in seconds.
+    pub samples_secs: Vec<f64>,
+    pub mean_secs: f64,
+    pub median_secs: f64,
+    pub min_secs: f64,
+    pub max_secs: f64,
+    pub stddev_secs: f64,
+    /// Set when stddev/mean exceeds the noise threshold, so users know the
+    /// measurement isn't trustworthy without more iterations.
+    pub high_variance: bool,
+}
+
+/// Serialize `value` to stdout as pretty-printed JSON.
+///
+/// Panics if serialization fails, which only happens if one of the types
+/// above is constructed incorrectly — there is no recoverable path for a
+/// CLI that has already decided to emit JSON.
+pub fn emit_json<T: Serialize>(value: &T) {
+    let json = serde_json::to_string_pretty(value).expect("structured output type must serialize");
+    println!("{json}");
+}
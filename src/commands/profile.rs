@@ -1,9 +1,250 @@
 use colored::Colorize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::time::Instant;
+
+use super::output::{self, BenchReport, OutputFormat, ProfileReport, StageStatus};
+use super::runner::{self, Cmd};
+
+/// Flag a run as noisy once sample-to-sample spread passes this fraction of the mean.
+const HIGH_VARIANCE_THRESHOLD: f64 = 0.15;
+
+const BENCH_ARTIFACT_PATH: &str = "flintx_output/bench.json";
+
+/// Runs the profiler. Returns `false` when a `--bench` run fails, so `main`
+/// can exit non-zero — CI steps gating on exit code must see a broken
+/// benchmark as a failure, not a success with an error printed alongside it.
+pub fn execute(target: &str, format: OutputFormat, bench: bool, iterations: u32, warmup: u32) -> bool {
+    if bench {
+        return run_bench(target, format, iterations, warmup);
+    }
+
+    let interpreter = runner::detect_interpreter();
+
+    if format.is_json() {
+        let report = ProfileReport {
+            target: target.to_string(),
+            status: StageStatus::NotConnected,
+            samples: Vec::new(),
+            interpreter,
+        };
+        output::emit_json(&report);
+        return true;
+    }
 
-pub fn execute(target: &str) {
     println!("{}", "[ PROFILE ] Running runtime profiler...".yellow().bold());
     println!("  Target: {}", target.cyan());
+    match &interpreter {
+        Some(version) => println!("  Interpreter: {}", version.cyan()),
+        None => println!("  Interpreter: {}", "not found on PATH".red()),
+    }
     println!();
     println!("{}", "  → Runtime profiler not yet connected.".dimmed());
     println!("{}", "  → Phase 4 will wire cProfile + psutil here.".dimmed());
-}
\ No newline at end of file
+    true
+}
+
+fn run_bench(target: &str, format: OutputFormat, iterations: u32, warmup: u32) -> bool {
+    if !format.is_json() {
+        println!("{}", "[ PROFILE ] Running statistical benchmark...".yellow().bold());
+        println!("  Target: {}", target.cyan());
+        println!("  Iterations: {} (+ {} warmup)", iterations, warmup);
+        println!();
+    }
+
+    let interpreter = runner::python_interpreter();
+
+    for i in 0..warmup {
+        if let Err(message) = run_one(interpreter, target, "warmup", i + 1, warmup) {
+            report_bench_error(format, &message);
+            return false;
+        }
+        if !format.is_json() {
+            println!("  {} warmup {}/{}", "→".dimmed(), i + 1, warmup);
+        }
+    }
+
+    let mut samples_secs = Vec::with_capacity(iterations as usize);
+    for i in 0..iterations {
+        let start = Instant::now();
+        if let Err(message) = run_one(interpreter, target, "iteration", i + 1, iterations) {
+            report_bench_error(format, &message);
+            return false;
+        }
+        let elapsed = start.elapsed().as_secs_f64();
+        samples_secs.push(elapsed);
+        if !format.is_json() {
+            println!("  {} sample {}/{}: {:.4}s", "→".dimmed(), i + 1, iterations, elapsed);
+        }
+    }
+
+    if samples_secs.is_empty() {
+        report_bench_error(format, "no samples collected, cannot compute statistics");
+        return false;
+    }
+
+    let report = BenchReport {
+        target: target.to_string(),
+        iterations,
+        warmup,
+        mean_secs: mean(&samples_secs),
+        median_secs: median(&samples_secs),
+        min_secs: samples_secs.iter().cloned().fold(f64::INFINITY, f64::min),
+        max_secs: samples_secs.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+        stddev_secs: stddev(&samples_secs),
+        high_variance: false,
+        samples_secs,
+    };
+    let high_variance = is_high_variance(report.mean_secs, report.stddev_secs);
+    let report = BenchReport { high_variance, ..report };
+
+    write_bench_artifact(&report);
+
+    if format.is_json() {
+        output::emit_json(&report);
+    } else {
+        println!();
+        println!("{}", "  Results:".bold());
+        println!("    mean:   {:.4}s", report.mean_secs);
+        println!("    median: {:.4}s", report.median_secs);
+        println!("    min:    {:.4}s", report.min_secs);
+        println!("    max:    {:.4}s", report.max_secs);
+        println!("    stddev: {:.4}s", report.stddev_secs);
+        if report.high_variance {
+            println!(
+                "{}",
+                "  ⚠ High variance detected — measurements may be noisy, consider more iterations."
+                    .yellow()
+            );
+        }
+        println!();
+        println!("  {} {}", "✔ Wrote".green(), BENCH_ARTIFACT_PATH.cyan());
+    }
+
+    true
+}
+
+/// Run the target script once and report failure (spawn error or non-zero
+/// exit) as a single descriptive message, stderr included so the user can
+/// see why the script broke without re-running it outside flintx.
+fn run_one(interpreter: &str, target: &str, kind: &str, index: u32, total: u32) -> Result<(), String> {
+    let output = Cmd::new(interpreter)
+        .arg(target)
+        .run_captured()
+        .map_err(|err| err.to_string())?;
+
+    if !output.success() {
+        let stderr = output.stderr.trim();
+        let detail = if stderr.is_empty() { "(no stderr output)" } else { stderr };
+        return Err(format!(
+            "{kind} {index}/{total} exited with {}, aborting benchmark:\n{detail}",
+            output.status
+        ));
+    }
+
+    Ok(())
+}
+
+fn report_bench_error(format: OutputFormat, message: &str) {
+    if format.is_json() {
+        output::emit_json(&serde_json::json!({ "error": message }));
+    } else {
+        eprintln!("{} {}", "  ✘ Benchmark failed:".red().bold(), message);
+    }
+}
+
+fn mean(samples: &[f64]) -> f64 {
+    samples.iter().sum::<f64>() / samples.len() as f64
+}
+
+fn median(samples: &[f64]) -> f64 {
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    }
+}
+
+fn stddev(samples: &[f64]) -> f64 {
+    let m = mean(samples);
+    let variance = samples.iter().map(|x| (x - m).powi(2)).sum::<f64>() / samples.len() as f64;
+    variance.sqrt()
+}
+
+/// A run is noisy once stddev/mean passes [`HIGH_VARIANCE_THRESHOLD`].
+fn is_high_variance(mean_secs: f64, stddev_secs: f64) -> bool {
+    mean_secs > 0.0 && stddev_secs / mean_secs > HIGH_VARIANCE_THRESHOLD
+}
+
+/// Merge this run's report into `flintx_output/bench.json`, keyed by script
+/// path, so `optimize` can compare before/after timings across runs.
+fn write_bench_artifact(report: &BenchReport) {
+    fs::create_dir_all("flintx_output").expect("Failed to create output directory");
+
+    let mut artifact: BTreeMap<String, BenchReport> = fs::read_to_string(BENCH_ARTIFACT_PATH)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default();
+
+    artifact.insert(report.target.clone(), report.clone());
+
+    let json = serde_json::to_string_pretty(&artifact).expect("bench artifact must serialize");
+    fs::write(BENCH_ARTIFACT_PATH, format!("{json}\n")).expect("Failed to write bench artifact");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLES: [f64; 5] = [1.0, 2.0, 3.0, 4.0, 5.0];
+
+    #[test]
+    fn mean_of_known_samples() {
+        assert_eq!(mean(&SAMPLES), 3.0);
+    }
+
+    #[test]
+    fn median_of_odd_length_samples() {
+        assert_eq!(median(&SAMPLES), 3.0);
+    }
+
+    #[test]
+    fn median_of_even_length_samples_averages_middle_pair() {
+        assert_eq!(median(&[1.0, 2.0, 3.0, 4.0]), 2.5);
+    }
+
+    #[test]
+    fn median_does_not_require_pre_sorted_input() {
+        assert_eq!(median(&[5.0, 1.0, 3.0, 2.0, 4.0]), 3.0);
+    }
+
+    #[test]
+    fn stddev_of_known_samples() {
+        // Population variance of [1,2,3,4,5] is 2.0, so stddev is sqrt(2).
+        assert!((stddev(&SAMPLES) - 2.0_f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn stddev_of_identical_samples_is_zero() {
+        assert_eq!(stddev(&[2.0, 2.0, 2.0]), 0.0);
+    }
+
+    #[test]
+    fn high_variance_flags_above_threshold() {
+        assert!(is_high_variance(1.0, HIGH_VARIANCE_THRESHOLD + 0.01));
+    }
+
+    #[test]
+    fn high_variance_allows_at_or_below_threshold() {
+        assert!(!is_high_variance(1.0, HIGH_VARIANCE_THRESHOLD));
+        assert!(!is_high_variance(1.0, HIGH_VARIANCE_THRESHOLD - 0.01));
+    }
+
+    #[test]
+    fn high_variance_is_false_for_zero_mean() {
+        assert!(!is_high_variance(0.0, 1.0));
+    }
+}
@@ -1,6 +1,39 @@
 use colored::Colorize;
 
-pub fn execute(target: &str) {
+use super::output::{self, OutputFormat, PipelinePlan, StageResult, StageStatus};
+
+pub fn execute(target: &str, format: OutputFormat) {
+    if format.is_json() {
+        let plan = PipelinePlan {
+            target: target.to_string(),
+            stages: vec![
+                StageResult {
+                    stage: "analyze".to_string(),
+                    inputs: vec![target.to_string()],
+                    outputs: vec!["flintx_output/analysis.json".to_string()],
+                    status: StageStatus::NotConnected,
+                    detail: Some("Phase 3 will wire the Python AST analyzer here.".to_string()),
+                },
+                StageResult {
+                    stage: "profile".to_string(),
+                    inputs: vec![target.to_string()],
+                    outputs: vec!["flintx_output/profile.json".to_string()],
+                    status: StageStatus::NotConnected,
+                    detail: Some("Phase 4 will wire cProfile + psutil here.".to_string()),
+                },
+                StageResult {
+                    stage: "optimize".to_string(),
+                    inputs: vec!["flintx_output/analysis.json".to_string(), "flintx_output/profile.json".to_string()],
+                    outputs: vec!["flintx_output/optimize.json".to_string()],
+                    status: StageStatus::NotConnected,
+                    detail: Some("Phase 5 will wire FastAPI + Ollama here.".to_string()),
+                },
+            ],
+        };
+        output::emit_json(&plan);
+        return;
+    }
+
     println!("{}", "[ RUN ] Executing full Flint-X pipeline...".yellow().bold());
     println!("  Target: {}", target.cyan());
     println!();
@@ -11,4 +44,4 @@ pub fn execute(target: &str) {
     println!();
     println!("{}", "  → Full pipeline not yet connected.".dimmed());
     println!("{}", "  → Phase 6 will wire all stages together here.".dimmed());
-}
\ No newline at end of file
+}
@@ -0,0 +1,176 @@
+//! Cross-platform subprocess execution shared by every phase that shells out
+//! to a Python tool (the AST analyzer, `cProfile`/`psutil`, `ollama`/FastAPI).
+//!
+//! This centralizes process plumbing — Windows vs Unix interpreter names,
+//! argument handling, and capturing vs streaming output — so each command
+//! doesn't reimplement it.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Stdio};
+
+/// Name of the Python interpreter to invoke on the current platform.
+///
+/// Windows installs of Python typically expose only `python`, while most
+/// Unix distributions reserve that name for Python 2 (or nothing at all)
+/// and expose `python3`.
+pub fn python_interpreter() -> &'static str {
+    if cfg!(windows) {
+        "python"
+    } else {
+        "python3"
+    }
+}
+
+/// Run `<interpreter> --version` and return the trimmed version string, or
+/// `None` if no interpreter could be found on PATH.
+pub fn detect_interpreter() -> Option<String> {
+    let output = Cmd::new(python_interpreter()).arg("--version").run_captured().ok()?;
+    if !output.success() {
+        return None;
+    }
+    // CPython prints the version to stdout on 3.4+, stderr on older builds.
+    let text = if !output.stdout.trim().is_empty() {
+        output.stdout
+    } else {
+        output.stderr
+    };
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        None
+    } else {
+        Some(trimmed.to_string())
+    }
+}
+
+/// Captured stdout/stderr from a completed subprocess.
+#[derive(Debug)]
+pub struct CmdOutput {
+    pub status: ExitStatus,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl CmdOutput {
+    pub fn success(&self) -> bool {
+        self.status.success()
+    }
+}
+
+#[derive(Debug)]
+pub enum RunnerError {
+    /// The process could not be spawned at all (e.g. interpreter not on PATH).
+    Spawn { program: String, source: std::io::Error },
+    /// The process ran but wrote output that wasn't valid UTF-8.
+    NonUtf8Output { program: String },
+}
+
+impl fmt::Display for RunnerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunnerError::Spawn { program, source } => {
+                write!(f, "failed to run `{program}`: {source}")
+            }
+            RunnerError::NonUtf8Output { program } => {
+                write!(f, "`{program}` produced output that was not valid UTF-8")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RunnerError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RunnerError::Spawn { source, .. } => Some(source),
+            RunnerError::NonUtf8Output { .. } => None,
+        }
+    }
+}
+
+/// Builder for a subprocess invocation, run either capturing output or
+/// streaming it through to the parent's stdout/stderr.
+pub struct Cmd {
+    program: String,
+    args: Vec<String>,
+    cwd: Option<PathBuf>,
+}
+
+impl Cmd {
+    pub fn new(program: impl Into<String>) -> Self {
+        Cmd {
+            program: program.into(),
+            args: Vec::new(),
+            cwd: None,
+        }
+    }
+
+    pub fn arg(mut self, arg: impl Into<String>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn current_dir(mut self, dir: impl AsRef<Path>) -> Self {
+        self.cwd = Some(dir.as_ref().to_path_buf());
+        self
+    }
+
+    fn build(&self) -> Command {
+        let mut command = Command::new(&self.program);
+        command.args(&self.args);
+        if let Some(cwd) = &self.cwd {
+            command.current_dir(cwd);
+        }
+        command
+    }
+
+    /// Run the command, capturing stdout/stderr as `String`s.
+    pub fn run_captured(&self) -> Result<CmdOutput, RunnerError> {
+        let output = self
+            .build()
+            .stdin(Stdio::null())
+            .output()
+            .map_err(|source| RunnerError::Spawn {
+                program: self.program.clone(),
+                source,
+            })?;
+
+        let stdout = String::from_utf8(output.stdout).map_err(|_| RunnerError::NonUtf8Output {
+            program: self.program.clone(),
+        })?;
+        let stderr = String::from_utf8(output.stderr).map_err(|_| RunnerError::NonUtf8Output {
+            program: self.program.clone(),
+        })?;
+
+        Ok(CmdOutput {
+            status: output.status,
+            stdout,
+            stderr,
+        })
+    }
+
+    /// Run the command with stdout/stderr inherited from this process, so
+    /// output streams live (e.g. a long `ollama` or FastAPI invocation).
+    #[allow(dead_code)]
+    pub fn run_streaming(&self) -> Result<ExitStatus, RunnerError> {
+        self.build()
+            .stdin(Stdio::null())
+            .stdout(Stdio::inherit())
+            .stderr(Stdio::inherit())
+            .status()
+            .map_err(|source| RunnerError::Spawn {
+                program: self.program.clone(),
+                source,
+            })
+    }
+}
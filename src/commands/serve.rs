@@ -0,0 +1,146 @@
+//! Long-lived JSON-RPC server mode (`flintx serve --stdio`).
+//!
+//! Keeps the AST analyzer and AI layer warm between requests so editors get
+//! near-interactive feedback without paying process-startup cost on every
+//! file. Results carry `OptimizationHint`s (line/column plus suggested
+//! replacement text) rather than the batch CLI's flat findings list, since
+//! an editor integration needs a location to anchor each hint to.
+
+use std::io::{self, BufRead, Write};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::output::{ServeAnalysisResult, StageStatus};
+use super::runner;
+
+/// A JSON-RPC 2.0 request, as sent one-per-line over stdin.
+#[derive(Deserialize)]
+struct RpcRequest {
+    #[allow(dead_code)]
+    jsonrpc: String,
+    #[serde(default)]
+    id: Value,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+/// A JSON-RPC 2.0 response, written one-per-line to stdout.
+#[derive(Serialize)]
+struct RpcResponse {
+    jsonrpc: &'static str,
+    id: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<RpcError>,
+}
+
+#[derive(Serialize)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+/// Params for the `analyze` method: the path to a Python file to analyze.
+#[derive(Deserialize)]
+struct AnalyzeParams {
+    path: String,
+}
+
+const PARSE_ERROR: i64 = -32700;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+pub fn execute(stdio: bool) {
+    if !stdio {
+        eprintln!("error: only --stdio transport is currently supported; pass --stdio");
+        return;
+    }
+
+    // Detected once and reused for every request, so the warm server doesn't
+    // pay interpreter-lookup cost per file the way a fresh `analyze` would.
+    let interpreter = runner::detect_interpreter();
+
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+
+    for line in stdin.lock().lines() {
+        let line = match line {
+            Ok(line) => line,
+            Err(_) => break,
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let response = handle_line(&line, &interpreter);
+        let json = serde_json::to_string(&response).expect("RpcResponse must serialize");
+        writeln!(out, "{json}").ok();
+        out.flush().ok();
+    }
+}
+
+fn handle_line(line: &str, interpreter: &Option<String>) -> RpcResponse {
+    let request: RpcRequest = match serde_json::from_str(line) {
+        Ok(request) => request,
+        Err(err) => {
+            return RpcResponse {
+                jsonrpc: "2.0",
+                id: Value::Null,
+                result: None,
+                error: Some(RpcError {
+                    code: PARSE_ERROR,
+                    message: format!("invalid JSON-RPC request: {err}"),
+                }),
+            }
+        }
+    };
+
+    match request.method.as_str() {
+        "analyze" => handle_analyze(request.id, request.params, interpreter),
+        other => RpcResponse {
+            jsonrpc: "2.0",
+            id: request.id,
+            result: None,
+            error: Some(RpcError {
+                code: METHOD_NOT_FOUND,
+                message: format!("unknown method `{other}`"),
+            }),
+        },
+    }
+}
+
+fn handle_analyze(id: Value, params: Value, interpreter: &Option<String>) -> RpcResponse {
+    let params: AnalyzeParams = match serde_json::from_value(params) {
+        Ok(params) => params,
+        Err(err) => {
+            return RpcResponse {
+                jsonrpc: "2.0",
+                id,
+                result: None,
+                error: Some(RpcError {
+                    code: INVALID_PARAMS,
+                    message: format!("expected {{\"path\": string}}: {err}"),
+                }),
+            }
+        }
+    };
+
+    // Static analysis engine not yet connected — see commands::analyze.
+    let result = ServeAnalysisResult {
+        target: params.path,
+        status: StageStatus::NotConnected,
+        hints: Vec::new(),
+        interpreter: interpreter.clone(),
+    };
+
+    RpcResponse {
+        jsonrpc: "2.0",
+        id,
+        result: Some(serde_json::to_value(result).expect("ServeAnalysisResult must serialize")),
+        error: None,
+    }
+}
@@ -3,6 +3,8 @@ mod commands;
 use clap::{Parser, Subcommand};
 use colored::Colorize;
 
+use commands::output::OutputFormat;
+
 #[derive(Parser)]
 #[command(
     name = "flintx",
@@ -11,6 +13,10 @@ use colored::Colorize;
     about = "AI-driven performance intelligence tool aligned with AMD hardware"
 )]
 struct Cli {
+    /// Output format: human-readable text or a single structured JSON document
+    #[arg(long, global = true, value_enum, default_value = "text")]
+    format: OutputFormat,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -18,7 +24,12 @@ struct Cli {
 #[derive(Subcommand)]
 enum Commands {
     /// Initialize a new Flint-X project in the current directory
-    Init,
+    Init {
+        /// Workload profile to scaffold (cpu-bound, rocm-gpu, web-service, minimal).
+        /// Prompts interactively when omitted.
+        #[arg(short, long, value_enum)]
+        profile: Option<commands::init::Profile>,
+    },
 
     /// Run static analysis on a Python source file
     Analyze {
@@ -32,6 +43,18 @@ enum Commands {
         /// Path to the Python script to profile
         #[arg(short, long)]
         target: String,
+
+        /// Run the script repeatedly and report timing statistics instead of a single pass
+        #[arg(long)]
+        bench: bool,
+
+        /// Number of timed iterations to collect in --bench mode
+        #[arg(long, default_value_t = 10, requires = "bench", value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+
+        /// Number of untimed warmup iterations to discard in --bench mode
+        #[arg(long, default_value_t = 3, requires = "bench")]
+        warmup: u32,
     },
 
     /// Query the AI layer for optimization recommendations
@@ -47,18 +70,43 @@ enum Commands {
         #[arg(short, long)]
         target: String,
     },
+
+    /// Run Flint-X as a long-lived server, keeping the analyzer and AI layer warm
+    Serve {
+        /// Speak JSON-RPC over stdin/stdout (currently the only supported transport)
+        #[arg(long)]
+        stdio: bool,
+    },
 }
 
 fn main() {
-    print_banner();
     let cli = Cli::parse();
 
+    // The server speaks JSON-RPC on stdout; any stray text would corrupt the stream.
+    let is_serve = matches!(cli.command, Commands::Serve { .. });
+
+    if cli.format.is_json() || is_serve {
+        colored::control::set_override(false);
+    } else {
+        print_banner();
+    }
+
     match cli.command {
-        Commands::Init => commands::init::execute(),
-        Commands::Analyze { target } => commands::analyze::execute(&target),
-        Commands::Profile { target } => commands::profile::execute(&target),
-        Commands::Optimize { input } => commands::optimize::execute(input),
-        Commands::Run { target } => commands::run::execute(&target),
+        Commands::Init { profile } => commands::init::execute(profile),
+        Commands::Analyze { target } => commands::analyze::execute(&target, cli.format),
+        Commands::Profile {
+            target,
+            bench,
+            iterations,
+            warmup,
+        } => {
+            if !commands::profile::execute(&target, cli.format, bench, iterations, warmup) {
+                std::process::exit(1);
+            }
+        }
+        Commands::Optimize { input } => commands::optimize::execute(input, cli.format),
+        Commands::Run { target } => commands::run::execute(&target, cli.format),
+        Commands::Serve { stdio } => commands::serve::execute(stdio),
     }
 }
 